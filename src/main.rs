@@ -1,6 +1,8 @@
 use std::fmt::Write;
 use std::num::NonZeroU64;
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
 use std::{
     ffi::OsString,
     num::NonZeroUsize,
@@ -13,17 +15,20 @@ use clap::Parser;
 use futures_util::StreamExt;
 use parking_lot::Mutex;
 use quantiles::ckms::CKMS;
-use reqwest::Response;
+use regex::Regex;
 use reqwest::{
-    header::{HeaderMap, HeaderValue},
+    header::{HeaderMap, HeaderName, HeaderValue},
     Method, Url,
 };
+use serde::Deserialize;
 use spdlog::{debug, error, info, Level, LevelFilter};
 
 #[derive(clap::Parser, Debug)]
 struct Args {
     count: NonZeroU64,
-    url: Url,
+
+    #[arg(required_unless_present = "scenario")]
+    url: Option<Url>,
 
     #[arg(short = 'X', long = "request", default_value_t = Method::GET)]
     method: Method,
@@ -50,6 +55,336 @@ struct Args {
         help = "Suppress output of requests, including errors which then will only be printed at the end"
     )]
     silent: bool,
+
+    #[arg(
+        long,
+        help = "Serve live Prometheus metrics (hload_requests_total, hload_errors_total, hload_rps, hload_request_duration_ms) on this host:port while the run is in progress"
+    )]
+    metrics_addr: Option<std::net::SocketAddr>,
+
+    #[arg(
+        long,
+        help = "Pace requests at a fixed rate (requests/sec) instead of waiting for each to complete before sending the next (open-loop, coordinated-omission corrected latency)"
+    )]
+    rate: Option<f64>,
+
+    #[arg(
+        long,
+        conflicts_with = "url",
+        help = "Replay a weighted mix of requests described in a TOML or JSON scenario file instead of hitting a single URL"
+    )]
+    scenario: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Comma-separated status codes/ranges that count as a pass (e.g. 200,201,429 or 200-299). Defaults to any non-4xx/5xx status"
+    )]
+    expect_status: Option<String>,
+
+    #[arg(
+        long,
+        help = "Only count a response as passing if its body contains this text, or matches it as a regex"
+    )]
+    expect_body: Option<String>,
+}
+
+/// Parse a `--expect-status` spec such as `"200,201,429"` or `"200-299"` into
+/// a list of inclusive status code ranges.
+fn parse_status_ranges(spec: &str) -> Result<Vec<(u16, u16)>, String> {
+    spec.split(',')
+        .map(|part| {
+            let part = part.trim();
+            if let Some((lo, hi)) = part.split_once('-') {
+                let lo: u16 = lo
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid status range: {part:?}"))?;
+                let hi: u16 = hi
+                    .trim()
+                    .parse()
+                    .map_err(|_| format!("Invalid status range: {part:?}"))?;
+                Ok((lo, hi))
+            } else {
+                let code: u16 = part
+                    .parse()
+                    .map_err(|_| format!("Invalid status code: {part:?}"))?;
+                Ok((code, code))
+            }
+        })
+        .collect()
+}
+
+fn status_in_ranges(ranges: &[(u16, u16)], code: u16) -> bool {
+    ranges.iter().any(|&(lo, hi)| (lo..=hi).contains(&code))
+}
+
+/// A `--expect-body` pattern: matches if the body contains it verbatim, or
+/// (when it also happens to be a valid regex) matches it as one. This way a
+/// literal substring containing regex metacharacters, e.g. `(error)` or
+/// `$5.00`, still matches as a plain substring even though it isn't a no-op
+/// as a regex.
+struct BodyExpectation {
+    literal: String,
+    regex: Option<Regex>,
+}
+
+impl BodyExpectation {
+    fn new(spec: &str) -> Self {
+        Self {
+            literal: spec.to_string(),
+            regex: Regex::new(spec).ok(),
+        }
+    }
+
+    fn is_match(&self, body: &str) -> bool {
+        body.contains(&self.literal) || self.regex.as_ref().is_some_and(|re| re.is_match(body))
+    }
+}
+
+/// One entry of a `--scenario` file: `{ method, url, headers, body, weight, name }`.
+/// `weight` controls how often the entry is picked relative to its siblings
+/// (default 1); `name` labels it in the per-endpoint summary (defaults to `url`).
+#[derive(Deserialize)]
+struct ScenarioEntry {
+    #[serde(default = "ScenarioEntry::default_method")]
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: Vec<String>,
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    weight: Option<u32>,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+impl ScenarioEntry {
+    fn default_method() -> String {
+        "GET".to_string()
+    }
+}
+
+#[derive(Deserialize)]
+struct Scenario {
+    requests: Vec<ScenarioEntry>,
+}
+
+/// Parse `raw` `"Key: Value"` header lines into a `HeaderMap`.
+fn build_headers(raw: &[String]) -> Result<HeaderMap, String> {
+    let mut headers = HeaderMap::with_capacity(raw.len());
+    for header in raw {
+        let Some((k, v)) = header.split_once(':') else {
+            return Err(format!("Malformed header: {header:?}"));
+        };
+
+        headers.insert(
+            HeaderName::from_bytes(k.trim().as_bytes()).map_err(|e| e.to_string())?,
+            HeaderValue::from_str(v.trim()).map_err(|e| e.to_string())?,
+        );
+    }
+    Ok(headers)
+}
+
+/// Load and build every request described by a `--scenario` file, paired with
+/// the display name and selection weight each was given.
+fn load_scenario(
+    client: &reqwest::Client,
+    path: &std::path::Path,
+) -> Result<Vec<(String, u32, reqwest::Request)>, String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+    let scenario: Scenario = if path.extension().is_some_and(|ext| ext == "json") {
+        serde_json::from_str(&contents).map_err(|e| e.to_string())?
+    } else {
+        toml::from_str(&contents).map_err(|e| e.to_string())?
+    };
+
+    scenario
+        .requests
+        .into_iter()
+        .map(|entry| {
+            let method = Method::from_str(&entry.method)
+                .map_err(|_| format!("Invalid method: {:?}", entry.method))?;
+            let url = Url::parse(&entry.url).map_err(|e| e.to_string())?;
+            let headers = build_headers(&entry.headers)?;
+            let name = entry.name.unwrap_or_else(|| entry.url.clone());
+            let weight = entry.weight.unwrap_or(1);
+
+            let mut request = client.request(method, url).headers(headers);
+            if let Some(body) = entry.body {
+                request = request.body(body);
+            }
+            let request = request.build().map_err(|e| e.to_string())?;
+
+            Ok((name, weight, request))
+        })
+        .collect()
+}
+
+/// A single latency/error accumulator, shared across worker tasks.
+struct Accumulator {
+    completed: AtomicU64,
+    errored: AtomicU64,
+    assertion_failures: AtomicU64,
+    elapsed_ms_sum: AtomicU64,
+    percentiles: Mutex<CKMS<f64>>,
+    bytes_total: AtomicU64,
+    body_sizes: Mutex<CKMS<f64>>,
+    ttfb: Mutex<CKMS<f64>>,
+}
+
+impl Accumulator {
+    fn new() -> Self {
+        Self {
+            completed: AtomicU64::new(0),
+            errored: AtomicU64::new(0),
+            assertion_failures: AtomicU64::new(0),
+            elapsed_ms_sum: AtomicU64::new(f64::to_bits(0.0)),
+            percentiles: Mutex::new(CKMS::<f64>::new(0.001)),
+            bytes_total: AtomicU64::new(0),
+            body_sizes: Mutex::new(CKMS::<f64>::new(0.001)),
+            ttfb: Mutex::new(CKMS::<f64>::new(0.001)),
+        }
+    }
+
+    /// Record a successful response. The mean is derived at summary time as
+    /// `elapsed_ms_sum / completed`, rather than pre-dividing by a fixed
+    /// expected count here, so per-endpoint means stay correct regardless of
+    /// how large that endpoint's actual share of the traffic turns out to be.
+    fn record_ok(&self, elapsed: Duration) {
+        self.percentiles
+            .lock()
+            .insert(elapsed.as_micros() as f64 / 1000.);
+        self.completed.fetch_add(1, Ordering::Relaxed);
+        _ = self
+            .elapsed_ms_sum
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                Some(f64::to_bits(
+                    f64::from_bits(n) + elapsed.as_micros() as f64 / 1000.,
+                ))
+            });
+    }
+
+    fn record_err(&self) {
+        self.errored.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_assertion_failure(&self) {
+        self.assertion_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_body(&self, bytes: u64) {
+        self.bytes_total.fetch_add(bytes, Ordering::Relaxed);
+        self.body_sizes.lock().insert(bytes as f64);
+    }
+
+    fn record_ttfb(&self, ttfb: Duration) {
+        self.ttfb.lock().insert(ttfb.as_micros() as f64 / 1000.);
+    }
+}
+
+/// Shared, thread-safe view of the in-flight run, polled by the optional
+/// metrics server and updated by every worker task.
+struct Metrics {
+    global: Accumulator,
+    per_request: Vec<(String, Accumulator)>,
+    total: u64,
+    sent: Arc<AtomicU64>,
+}
+
+impl Metrics {
+    fn new(names: &[String], total: u64, sent: Arc<AtomicU64>) -> Self {
+        Self {
+            global: Accumulator::new(),
+            per_request: names
+                .iter()
+                .map(|name| (name.clone(), Accumulator::new()))
+                .collect(),
+            total,
+            sent,
+        }
+    }
+
+    /// Render the current global state in Prometheus text exposition format.
+    fn render(&self, elapsed: Duration) -> String {
+        let errored = self.global.errored.load(Ordering::Relaxed);
+        // `sent` is the same slot counter the worker loop claims requests from,
+        // so it reflects a request as soon as a task claims its slot instead of
+        // waiting for it to finish (with `--rate`, that is before the slot's
+        // scheduled dispatch time), and it naturally covers completed,
+        // errored, and assertion-failed outcomes alike.
+        let total = self
+            .sent
+            .load(Ordering::Relaxed)
+            .saturating_sub(1)
+            .min(self.total);
+        let rps = total as f64 / elapsed.as_secs_f64();
+        let percentiles = self.global.percentiles.lock();
+
+        let mut out = String::new();
+        _ = writeln!(
+            out,
+            "# HELP hload_requests_total Total requests sent so far."
+        );
+        _ = writeln!(out, "# TYPE hload_requests_total counter");
+        _ = writeln!(out, "hload_requests_total {total}");
+
+        _ = writeln!(
+            out,
+            "# HELP hload_errors_total Total requests that errored so far."
+        );
+        _ = writeln!(out, "# TYPE hload_errors_total counter");
+        _ = writeln!(out, "hload_errors_total {errored}");
+
+        _ = writeln!(out, "# HELP hload_rps Requests sent per second so far.");
+        _ = writeln!(out, "# TYPE hload_rps gauge");
+        _ = writeln!(out, "hload_rps {rps:.04}");
+
+        _ = writeln!(
+            out,
+            "# HELP hload_request_duration_ms Request latency in milliseconds."
+        );
+        _ = writeln!(out, "# TYPE hload_request_duration_ms gauge");
+        for q in [0.0, 0.5, 0.75, 0.90, 0.99, 1.0] {
+            let v = percentiles.query(q).map_or(f64::NAN, |(_, v)| v);
+            _ = writeln!(out, "hload_request_duration_ms{{quantile=\"{q}\"}} {v:.04}");
+        }
+
+        out
+    }
+}
+
+/// Spawn a `tiny_http`-backed scrape endpoint on its own thread. The server
+/// recomputes its response from `metrics` on every request, so it always
+/// reflects the latest state of the run.
+fn spawn_metrics_server(
+    addr: std::net::SocketAddr,
+    metrics: Arc<Metrics>,
+    start: std::time::Instant,
+) {
+    std::thread::spawn(move || {
+        let server = match tiny_http::Server::http(addr) {
+            Ok(server) => server,
+            Err(e) => {
+                error!("Could not start metrics server on {addr}: {e}");
+                return;
+            }
+        };
+
+        info!("Serving Prometheus metrics on http://{addr}");
+        for request in server.incoming_requests() {
+            let body = metrics.render(start.elapsed());
+            let response = tiny_http::Response::from_string(body).with_header(
+                tiny_http::Header::from_bytes(
+                    &b"Content-Type"[..],
+                    &b"text/plain; version=0.0.4"[..],
+                )
+                .unwrap(),
+            );
+            _ = request.respond(response);
+        }
+    });
 }
 
 fn main() -> ExitCode {
@@ -82,31 +417,81 @@ fn start() -> ExitCode {
         .build()
         .unwrap();
 
-    let mut headers = HeaderMap::with_capacity(args.header.len());
-    let unparsed_headers = Box::leak(args.header.into_boxed_slice());
-    for header in unparsed_headers {
-        let Some((k, v)) = header.split_once(':') else {
-            error!("Malformed header: {header:?}");
-            return ExitCode::FAILURE;
-        };
+    // Either a single request (the historical `url`/`-X`/`-H`/`-d` args) or a
+    // weighted mix described by `--scenario` is built up front; the spawn
+    // loop below just picks one of `requests` on every iteration.
+    let (names, weights, requests): (Vec<String>, Vec<u32>, Vec<reqwest::Request>) =
+        if let Some(scenario) = &args.scenario {
+            let entries = match load_scenario(&client, scenario) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    error!("Could not load scenario {scenario:?}: {e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if entries.is_empty() {
+                error!("Scenario {scenario:?} contains no requests");
+                return ExitCode::FAILURE;
+            }
+            entries.into_iter().fold(
+                (Vec::new(), Vec::new(), Vec::new()),
+                |(mut names, mut weights, mut requests), (name, weight, request)| {
+                    names.push(name);
+                    weights.push(weight);
+                    requests.push(request);
+                    (names, weights, requests)
+                },
+            )
+        } else {
+            let headers = match build_headers(&args.header) {
+                Ok(headers) => headers,
+                Err(e) => {
+                    error!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            debug!("Headers: {headers:?}");
+
+            let url = args
+                .url
+                .clone()
+                .expect("url is required without --scenario");
+            let mut request = client
+                .request(args.method.clone(), url.clone())
+                .headers(headers);
+            if let Some(content) = args.data.clone() {
+                request = request.body(content.into_encoded_bytes());
+            }
+
+            let request = match request.build() {
+                Ok(r) => r,
+                Err(e) => {
+                    error!("Could not build request: {e:?}");
+                    return ExitCode::FAILURE;
+                }
+            };
 
-        headers.insert(k.trim(), HeaderValue::from_str(v.trim()).unwrap());
-    }
-    debug!("Headers: {headers:?}");
+            (vec![url.to_string()], vec![1], vec![request])
+        };
 
-    let mut request = client.request(args.method, args.url).headers(headers);
-    if let Some(content) = args.data {
-        request = request.body(content.into_encoded_bytes());
+    // Select by cumulative weight (binary search) rather than expanding each
+    // entry's weight into that many slots of an eagerly-collected bag: a
+    // scenario with a typo'd or malicious weight (up to u32::MAX) would
+    // otherwise allocate billions of entries before a single request is sent.
+    // A weight of 0 excludes the entry entirely instead of being clamped up.
+    let cum_weights: Vec<u64> = weights
+        .iter()
+        .scan(0u64, |total, &weight| {
+            *total += weight as u64;
+            Some(*total)
+        })
+        .collect();
+    let total_weight = *cum_weights.last().unwrap_or(&0);
+    if total_weight == 0 {
+        error!("Every request has weight 0; nothing to send");
+        return ExitCode::FAILURE;
     }
 
-    let request = match request.build() {
-        Ok(r) => r,
-        Err(e) => {
-            error!("Could not build request: {e:?}");
-            return ExitCode::FAILURE;
-        }
-    };
-
     let tasks = args.tasks.map_or_else(
         || {
             std::thread::available_parallelism()
@@ -117,22 +502,50 @@ fn start() -> ExitCode {
         NonZeroUsize::get,
     );
 
-    let idx = AtomicU64::from(1);
+    let expect_status = match args.expect_status.as_deref().map(parse_status_ranges) {
+        Some(Ok(ranges)) => Some(ranges),
+        Some(Err(e)) => {
+            error!("Invalid --expect-status: {e}");
+            return ExitCode::FAILURE;
+        }
+        None => None,
+    };
+
+    let expect_body = args.expect_body.as_deref().map(BodyExpectation::new);
+
+    let count = args.count.get();
+    let idx = Arc::new(AtomicU64::new(1));
     let err_msg = Mutex::new(String::new());
+    let assertion_msg = Mutex::new(String::new());
     let start = std::time::Instant::now();
+    let test_start = tokio::time::Instant::now();
+
+    if args
+        .rate
+        .is_some_and(|rate| !rate.is_finite() || rate <= 0.0)
+    {
+        error!("--rate must be a positive, finite number of requests/sec");
+        return ExitCode::FAILURE;
+    }
+    let interval = args.rate.map(|rate| Duration::from_secs_f64(1.0 / rate));
 
-    let percentiles = Mutex::new(CKMS::<f64>::new(0.001));
-    let mean = AtomicU64::from(f64::to_bits(0.0));
+    let metrics = Arc::new(Metrics::new(&names, count, Arc::clone(&idx)));
 
-    let count = args.count.get();
+    if let Some(addr) = args.metrics_addr {
+        spawn_metrics_server(addr, Arc::clone(&metrics), start);
+    }
     async_scoped::TokioScope::scope_and_block(|s| {
         for _ in 0..tasks {
             let client = &client;
-            let request = &request;
+            let requests = &requests;
+            let cum_weights = &cum_weights;
             let idx = &idx;
-            let percentiles = &percentiles;
-            let mean = &mean;
+            let metrics = Arc::clone(&metrics);
             let err_msg = &err_msg;
+            let assertion_msg = &assertion_msg;
+            let expect_status = &expect_status;
+            let expect_body = &expect_body;
+            let interval = interval;
 
             s.spawn(async move {
                 let mut buf = Vec::new();
@@ -143,67 +556,133 @@ fn start() -> ExitCode {
                         break;
                     }
 
+                    let slot = (idx - 1) % total_weight;
+                    let req_idx = cum_weights.partition_point(|&w| w <= slot);
+                    let request = &requests[req_idx];
+
+                    // In open-loop (`--rate`) mode, each request slot has an intended
+                    // start time regardless of when earlier requests actually finished.
+                    // Sleeping to that deadline and then measuring latency from it (not
+                    // from the actual dispatch time) folds any backlog into the reported
+                    // latency instead of hiding it, i.e. the coordinated-omission fix.
+                    let scheduled =
+                        interval.map(|interval| test_start + interval.mul_f64((idx - 1) as f64));
+                    if let Some(scheduled) = scheduled {
+                        tokio::time::sleep_until(scheduled).await;
+                    }
+
                     let start = std::time::Instant::now();
-                    match client
-                        .execute(request.try_clone().unwrap())
-                        .await
-                        .and_then(Response::error_for_status)
-                    {
+                    let base = scheduled.map_or(start, tokio::time::Instant::into_std);
+                    match client.execute(request.try_clone().unwrap()).await {
                         Ok(res) => {
-                            let elapsed = start.elapsed();
-                            percentiles
-                                .lock()
-                                .insert(elapsed.as_micros() as f64 / 1000.);
-                            _ = mean.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
-                                Some(f64::to_bits(
-                                    f64::from_bits(n) + elapsed.as_micros() as f64 / count as f64,
-                                ))
-                            });
-
-                            if args.silent {
-                                continue;
-                            }
-
                             let status = res.status();
-
-                            let log_empty = || {
-                                info!(
-                                    "[{}/{}] [{}] in {:.02}ms",
-                                    idx,
-                                    count,
-                                    status,
-                                    elapsed.as_micros() as f64 / 1000.,
-                                );
-                            };
-
-                            if res.content_length().is_some_and(|n| n == 0) {
-                                log_empty();
-                                continue;
-                            }
+                            let content_length = res.content_length();
 
                             buf.clear();
                             let mut stream = res.bytes_stream();
+                            let mut first_byte_at = None;
                             while let Some(Ok(b)) = stream.next().await {
+                                first_byte_at.get_or_insert_with(std::time::Instant::now);
                                 buf.extend_from_slice(&b);
                             }
 
-                            if buf.is_empty() {
-                                log_empty();
-                                continue;
+                            // Captured only after the body is fully drained, so this is the
+                            // full request-to-last-byte duration, distinct from `ttfb` below.
+                            let elapsed = base.elapsed();
+
+                            let bytes = buf.len() as u64;
+                            metrics.global.record_body(bytes);
+                            metrics.per_request[req_idx].1.record_body(bytes);
+
+                            // Content-Length is known up front for buffered responses, so
+                            // there's nothing distinct to measure; chunked/streamed responses
+                            // (Content-Length absent) get a dedicated time-to-first-byte stat.
+                            if content_length.is_none() {
+                                if let Some(first_byte_at) = first_byte_at {
+                                    let ttfb = first_byte_at.duration_since(base);
+                                    metrics.global.record_ttfb(ttfb);
+                                    metrics.per_request[req_idx].1.record_ttfb(ttfb);
+                                }
                             }
 
-                            let str = String::from_utf8_lossy(&buf);
-                            info!(
-                                "[{}/{}] [{}] in {:.02}ms: {}",
-                                idx,
-                                count,
-                                status,
-                                elapsed.as_micros() as f64 / 1000.,
-                                str
+                            let body = String::from_utf8_lossy(&buf);
+                            let status_ok = expect_status.as_ref().map_or_else(
+                                || !status.is_client_error() && !status.is_server_error(),
+                                |ranges| status_in_ranges(ranges, status.as_u16()),
                             );
+                            let body_ok = expect_body
+                                .as_ref()
+                                .map_or(true, |exp| exp.is_match(&body));
+
+                            if status_ok && body_ok {
+                                metrics.global.record_ok(elapsed);
+                                metrics.per_request[req_idx].1.record_ok(elapsed);
+
+                                if args.silent {
+                                    continue;
+                                }
+
+                                if buf.is_empty() {
+                                    info!(
+                                        "[{}/{}] [{}] in {:.02}ms",
+                                        idx,
+                                        count,
+                                        status,
+                                        elapsed.as_micros() as f64 / 1000.,
+                                    );
+                                } else {
+                                    info!(
+                                        "[{}/{}] [{}] in {:.02}ms: {}",
+                                        idx,
+                                        count,
+                                        status,
+                                        elapsed.as_micros() as f64 / 1000.,
+                                        body
+                                    );
+                                }
+                            } else {
+                                metrics.global.record_assertion_failure();
+                                metrics.per_request[req_idx].1.record_assertion_failure();
+
+                                let mut reasons = Vec::new();
+                                if !status_ok {
+                                    reasons.push("unexpected status".to_string());
+                                }
+                                if !body_ok {
+                                    reasons.push("body mismatch".to_string());
+                                }
+                                let reason = reasons.join(", ");
+
+                                if !args.silent {
+                                    error!(
+                                        "[{}/{}] [{}] in {:.02}ms: assertion failed ({}): {}",
+                                        idx,
+                                        count,
+                                        status,
+                                        elapsed.as_micros() as f64 / 1000.,
+                                        reason,
+                                        body
+                                    );
+                                }
+
+                                let mut assertion_msg = assertion_msg.lock();
+                                if assertion_msg.is_empty() {
+                                    _ = writeln!(
+                                        assertion_msg,
+                                        "Assertion failures:\n- [{idx}/{count}] [{status}]: {reason}"
+                                    );
+                                } else {
+                                    _ = writeln!(
+                                        assertion_msg,
+                                        "- [{idx}/{count}] [{status}]: {reason}"
+                                    );
+                                }
+                            }
                         }
                         Err(e) => {
-                            let elapsed = start.elapsed();
+                            let elapsed = base.elapsed();
+                            metrics.global.record_err();
+                            metrics.per_request[req_idx].1.record_err();
                             if !args.silent {
                                 if let Some(status) = e.status() {
                                     error!(
@@ -247,22 +726,65 @@ fn start() -> ExitCode {
         exit = ExitCode::FAILURE;
     }
 
-    let percentiles = percentiles.into_inner();
-
-    info!(
-        "Sent {} requests in {:.04}s ({:.02} rps / {:.02}ms mean)\n- Stats: [ p0 (min): {:.02}ms / p1: {:.02}ms / p25: {:.02}ms / p50 (median): {:.02}ms / p75: {:.02}ms / p99: {:.02}ms / p100 (max): {:.02}ms ]",
-        count,
-        elapsed.as_millis() as f64 / 1000.,
-        (count as f64 / elapsed.as_secs_f64()),
-        f64::from_bits(mean.load(Ordering::Relaxed)) / 1000.,
-        percentiles.query(0.00).unwrap_or((0, f64::NAN)).1,
-        percentiles.query(0.01).unwrap_or((0, f64::NAN)).1,
-        percentiles.query(0.25).unwrap_or((0, f64::NAN)).1,
-        percentiles.query(0.50).unwrap_or((0, f64::NAN)).1,
-        percentiles.query(0.75).unwrap_or((0, f64::NAN)).1,
-        percentiles.query(0.99).unwrap_or((0, f64::NAN)).1,
-        percentiles.query(1.00).unwrap_or((0, f64::NAN)).1,
-    );
+    let assertion_msg = assertion_msg.into_inner();
+    if !assertion_msg.is_empty() {
+        error!("{}", &assertion_msg[..assertion_msg.len() - 1]);
+        exit = ExitCode::FAILURE;
+    }
+
+    let summarize = |label: &str, acc: &Accumulator, count: u64, elapsed: Duration| {
+        let percentiles = acc.percentiles.lock();
+        let body_sizes = acc.body_sizes.lock();
+        let ttfb = acc.ttfb.lock();
+        let bytes_total = acc.bytes_total.load(Ordering::Relaxed);
+        let completed = acc.completed.load(Ordering::Relaxed);
+        let mean = if completed == 0 {
+            f64::NAN
+        } else {
+            f64::from_bits(acc.elapsed_ms_sum.load(Ordering::Relaxed)) / completed as f64
+        };
+
+        info!(
+            "{} Sent {} requests in {:.04}s ({:.02} rps / {:.02}ms mean)\n- Stats: [ p0 (min): {:.02}ms / p1: {:.02}ms / p25: {:.02}ms / p50 (median): {:.02}ms / p75: {:.02}ms / p99: {:.02}ms / p100 (max): {:.02}ms ]\n- Throughput: {:.02} MiB/s ({} bytes total)\n- Body size: [ p0: {:.0}B / p50: {:.0}B / p99: {:.0}B / p100: {:.0}B ]\n- TTFB (chunked responses only): [ p50: {:.02}ms / p99: {:.02}ms ]\n- Assertion failures: {}",
+            label,
+            count,
+            elapsed.as_millis() as f64 / 1000.,
+            (count as f64 / elapsed.as_secs_f64()),
+            mean,
+            percentiles.query(0.00).unwrap_or((0, f64::NAN)).1,
+            percentiles.query(0.01).unwrap_or((0, f64::NAN)).1,
+            percentiles.query(0.25).unwrap_or((0, f64::NAN)).1,
+            percentiles.query(0.50).unwrap_or((0, f64::NAN)).1,
+            percentiles.query(0.75).unwrap_or((0, f64::NAN)).1,
+            percentiles.query(0.99).unwrap_or((0, f64::NAN)).1,
+            percentiles.query(1.00).unwrap_or((0, f64::NAN)).1,
+            bytes_total as f64 / 1024. / 1024. / elapsed.as_secs_f64(),
+            bytes_total,
+            body_sizes.query(0.00).unwrap_or((0, f64::NAN)).1,
+            body_sizes.query(0.50).unwrap_or((0, f64::NAN)).1,
+            body_sizes.query(0.99).unwrap_or((0, f64::NAN)).1,
+            body_sizes.query(1.00).unwrap_or((0, f64::NAN)).1,
+            ttfb.query(0.50).unwrap_or((0, f64::NAN)).1,
+            ttfb.query(0.99).unwrap_or((0, f64::NAN)).1,
+            acc.assertion_failures.load(Ordering::Relaxed),
+        );
+    };
+
+    summarize("[global]", &metrics.global, count, elapsed);
+
+    if metrics.per_request.len() > 1 {
+        for (name, acc) in &metrics.per_request {
+            let completed = acc.completed.load(Ordering::Relaxed);
+            let errored = acc.errored.load(Ordering::Relaxed);
+            let assertion_failures = acc.assertion_failures.load(Ordering::Relaxed);
+            summarize(
+                &format!("[{name}]"),
+                acc,
+                completed + errored + assertion_failures,
+                elapsed,
+            );
+        }
+    }
 
     exit
 }